@@ -1,6 +1,54 @@
+use std::env;
+use std::process::Command;
+
+/// Literal forms that are only available from a given compiler version onward, paired with the
+/// `cfg` their support is gated behind. Adding a newly-stabilized literal form is a one-line
+/// addition here - every entry's `cargo:rustc-check-cfg` is emitted unconditionally, so the
+/// `cfg` never trips an "unexpected cfg" warning on a toolchain that doesn't satisfy it.
+const FEATURE_CFGS: &[(u32, &str)] = &[
+    // C-string literals (`c"..."`) were stabilized in 1.79.
+    (79, "has_c_string"),
+];
+
+/// culit's minimum supported Rust version (see the `msrv` badge in `src/lib.rs`'s crate doc).
+const MSRV_MINOR: u32 = 58;
+
 fn main() {
-    println!("cargo:rustc-check-cfg=cfg(has_c_string)");
-    if version_check::is_min_version("1.79.0").unwrap_or(false) {
-        println!("cargo:rustc-cfg=has_c_string");
+    let detected_minor = rustc_minor_version();
+    let minor = detected_minor.unwrap_or(0);
+
+    for &(min_minor, cfg_name) in FEATURE_CFGS {
+        println!("cargo:rustc-check-cfg=cfg({cfg_name})");
+        if minor >= min_minor {
+            println!("cargo:rustc-cfg={cfg_name}");
+        }
     }
+
+    // Only warn when we're confident about the version we detected - a failed detection
+    // shouldn't be reported to the user as "your toolchain is too old".
+    if let Some(minor) = detected_minor {
+        if minor < MSRV_MINOR {
+            println!(
+                "cargo:warning=culit requires Rust 1.{MSRV_MINOR} or newer, but this toolchain is Rust 1.{minor}"
+            );
+            println!(
+                "cargo:warning=literals culit doesn't recognize will fail with opaque errors from deep inside its macro expansion rather than a clear version mismatch"
+            );
+        }
+    }
+}
+
+/// Parses the compiler's minor version out of `$RUSTC --version`, e.g. `"rustc 1.79.0 (...)"` ->
+/// `Some(79)`. Returns `None` on any failure to run `rustc` or to parse its output, in which case
+/// the caller falls back to treating the toolchain as too old for every gated feature.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+
+    let mut pieces = version.split('.');
+    if pieces.next()? != "rustc 1" {
+        return None;
+    }
+    pieces.next()?.parse().ok()
 }
@@ -35,10 +35,10 @@
 //!     pub mod integer {
 //!         macro_rules! nzusize {
 //!             // handle `0` specially
-//!             (0) => {
+//!             (0 $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 compile_error!("`0` is not a valid `NonZeroUsize`")
 //!             };
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 const { NonZeroUsize::new($value).unwrap() }
 //!             };
 //!         }
@@ -76,7 +76,7 @@
 //! mod custom_literal {
 //!     pub mod string {
 //!         macro_rules! f {
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal @ $_style:ident $_raw_source:literal @ $_kind:ident) => {
 //!                 format!($value)
 //!             };
 //!         }
@@ -106,7 +106,7 @@
 //!     pub mod integer {
 //!         // day
 //!         macro_rules! d {
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 Duration::from_secs(60 * 60 * 24 * $value)
 //!             };
 //!         }
@@ -114,7 +114,7 @@
 //!
 //!         // hour
 //!         macro_rules! h {
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 Duration::from_secs(60 * 60 * $value)
 //!             };
 //!         }
@@ -122,7 +122,7 @@
 //!
 //!         // minute
 //!         macro_rules! m {
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 Duration::from_secs(60 * $value)
 //!             };
 //!         }
@@ -130,7 +130,7 @@
 //!
 //!         // second
 //!         macro_rules! s {
-//!             ($value:literal) => {
+//!             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 //!                 Duration::from_secs($value)
 //!             };
 //!         }
@@ -146,28 +146,92 @@
 //! `#[culit]` replaces every literal that has a custom suffix with a call to the macro
 //! at `crate::custom_literal::<type>::<suffix>!(...)`, for example:
 //!
-//! - `100km` expands to `crate::custom_literal::int::km!(100)`
-//! - `70.8e7feet` expands to `crate::custom_literal::float::feet!(70 8 7)`
-//!     - `70` is the part before the decimal
-//!     - `8` is the part after the decimal. If missing like in `70e8` then it defaults to `0`
+//! - `100km` expands to `crate::custom_literal::integer::km!(100 "100" @ dec "100" @ integer)`
+//! - `70.8e7feet` expands to
+//!   `crate::custom_literal::decimal::feet!(70 8 1 7 "70.8" "70.8e7" @ decimal)`
+//!     - `70` is the integral part, before the decimal point
+//!     - `8` is the fractional part, after the decimal point
+//!     - `1` is the fractional digit count (`$frac_len`), since the fractional part alone loses
+//!       leading zeros - `1.007` and `1.7` both parse to fractional `7`, but have `$frac_len` `3`
+//!       and `1` respectively
 //!     - `7` is the exponent. If missing like in `70.0` then it defaults to `1`
-//! - `'a'ascii` expands to `crate::custom_literal::char::ascii!('a')`
-//! - `b'a'ascii` expands to `crate::custom_literal::byte_char::ascii!(97)`
-//! - `"foo"bar` expands to `crate::custom_literal::str::bar!("foo")`
-//! - `b"foo"bar` expands to `crate::custom_literal::byte_str::bar!(b"foo")`
-//! - `c"foo"bar` expands to `crate::custom_literal::c_str::bar!(c"foo")`
+//!     - `"70.8"` is the underscore-stripped mantissa text, for exact-decimal or fixed-point
+//!       suffixes that can't be reconstructed losslessly from the fields above
+//! - `'a'ascii` expands to `crate::custom_literal::character::ascii!('a' "'a'" @ character)`
+//! - `b'a'ascii` expands to
+//!   `crate::custom_literal::byte_character::ascii!(97 "b'a'" @ byte_character)`
+//! - `"foo"bar` expands to
+//!   `crate::custom_literal::string::bar!("foo" "foo" @ cooked "\"foo\"" @ string)`
+//! - `b"foo"bar` expands to
+//!   `crate::custom_literal::byte_string::bar!(b"foo" b"foo" @ cooked "b\"foo\"" @ byte_string)`
+//! - `c"foo"bar` expands to
+//!   `crate::custom_literal::c_string::bar!(c"foo" "c\"foo\"" @ c_string)`
+//!
+//! Every expansion above also carries an `@ dec`/`@ hex`/... base tag (integer only) and a
+//! trailing raw-source-text + `@ $kind` pair, elaborated on further down - they're included in
+//! the examples above to match what `#[culit]` actually produces, not abbreviated for brevity.
+//!
+//! By default literals are resolved at `crate::custom_literal`, but this can be redirected
+//! with `#[culit(path = some_crate::literals)]` to consume a reusable "literal pack" published
+//! by another crate, e.g. `#[culit(path = units::literals)]`.
+//!
+//! `#[culit(strict)]` additionally turns a suffix that's a near-miss typo of a real Rust suffix
+//! (e.g. `7i63`, `3.0f36`) into a "did you mean" error rather than silently dispatching it to
+//! `custom_literal`, where it would otherwise surface as an opaque "cannot find macro" error.
+//! This is off by default since it would otherwise misfire on a short custom suffix that happens
+//! to sit within a couple of edits of a real one, e.g. `id`.
+//!
+//! A leading `-` is folded into a numeric custom literal when it's in unary position, e.g.
+//! `-100km` expands to `crate::custom_literal::integer::km!(100 "100" @ dec @ neg "100" @ integer)`
+//! rather than leaving a
+//! separate `-` token in front of the expansion - this lets a macro represent the sign itself
+//! (e.g. a bignum with its own `Sign` field) instead of relying on the expansion implementing
+//! [`Neg`](std::ops::Neg). A `-` that's binary subtraction (`a - 100km`) is left untouched.
+//!
+//! Every custom literal, regardless of kind, additionally receives the literal's exact source
+//! text (underscores, digit-base prefix, quotes, escapes and all, minus the suffix) as a trailing
+//! string literal, followed by a `@ $kind` tag naming which `custom_literal` submodule it came
+//! from (e.g. `@ integer`) - e.g. `0x1_0_0km` expands to
+//! `crate::custom_literal::integer::km!(256 "100" @ hex "0x1_0_0" @ integer)`. This lets a macro
+//! reparse the verbatim input itself (bignum, fixed-point, base-N, ...) instead of being limited
+//! to whatever `litrs` already normalized into the earlier arguments.
+//!
+//! String and byte string custom literals also receive the pre-unescape source text alongside
+//! the usual (cooked) `$value`, plus a marker saying which one `$value` itself is - e.g.
+//! `"a\nb"path` expands to
+//! `crate::custom_literal::string::path!("a\nb" "a\\nb" @ cooked "\"a\\nb\"" @ string)`, where the
+//! second argument is the 4 literal characters the user wrote between the quotes (`a`, `\`, `n`,
+//! `b`), not the newline `$value` was already turned into. This lets a macro for things like
+//! embedded regexes or file paths use the text exactly as written. A raw string literal has
+//! nothing to undo, so its `@ raw` marker accompanies two identical arguments.
 //!
 //! ## Skeleton
 //!
-//! Here's a skeleton for the `custom_literal` module which must exist at `crate::custom_literal`.
+//! Here's a skeleton for the `custom_literal` module which must exist at `crate::custom_literal`
+//! (or at the module passed to `#[culit(path = ...)]`).
 //! This module adds a new literal for every type of literal:
 //!
 //! ```
 //! mod custom_literal {
 //!     pub mod integer {
 //!         // 0x100custom
+//!         //
+//!         // `$raw_digits` is the sign-free, underscore-stripped digit text as written
+//!         // (here `"100"`), for building values wider than `u128`.
+//!         //
+//!         // `$base` is one of `bin`/`oct`/`dec`/`hex`, wrapped in `$(...)?` so macros
+//!         // written before this argument existed keep matching
+//!         //
+//!         // `$sign` is `neg` when a leading unary `-` was folded into this literal (e.g.
+//!         // `-0x100custom`), so that `$value` stays an unsigned magnitude. It's only present
+//!         // when folded, so unsigned-only suffixes can keep matching without it. It always
+//!         // follows `@ $base`, so it's nested inside `$base`'s `$(...)?` - two sibling
+//!         // `$(@ $x:ident)?` groups would be ambiguous to `macro_rules!`
+//!         //
+//!         // `$raw_source` is the literal's exact source text minus the suffix (here
+//!         // `"0x100"`), and `$kind` is always `integer` - see [`crate`] above
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw_digits:literal $(@ $base:ident $(@ $sign:ident)?)? $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -180,8 +244,22 @@
 //!         // ^^ integral              70
 //!         //    ^^^^ fractional       3141
 //!         //         ^^^ exponent    -100
+//!         //
+//!         // `$frac_len` is the fractional digit count (here `4`), since `$fractional` alone
+//!         // loses leading zeros - `1.007` and `1.7` both parse to `$fractional` `7`, but have
+//!         // `$frac_len` `3` and `1` respectively. Reconstruct the exact value as
+//!         // `integral + fractional / 10^frac_len`.
+//!         //
+//!         // `$raw_mantissa` is the underscore-stripped `"70.3141"` text, for exact-decimal
+//!         // or fixed-point suffixes that can't be reconstructed losslessly from the above
+//!         //
+//!         // `$sign` is `neg` when a leading unary `-` was folded into this literal, see the
+//!         // identical explanation in `integer` above
+//!         //
+//!         // `$raw_source` and `$kind` work exactly like in `integer` above, with `$kind`
+//!         // always `decimal` here
 //!         macro_rules! custom {
-//!             ($integral:literal $fractional:literal $exponent:literal) => {
+//!             ($integral:literal $fractional:literal $frac_len:literal $exponent:literal $raw_mantissa:literal $(@ $sign:ident)? $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -189,10 +267,18 @@
 //!     }
 //!
 //!     pub mod string {
-//!         // "foo_bar"custom
-//!         // ^^^^^^^^^ value - "foo_bar"
+//!         // "foo\nbar"custom
+//!         // ^^^^^^^^^^ value - "foo\nbar" (escapes already processed)
+//!         //
+//!         // `$raw` is the pre-unescape source text - for `"foo\nbar"` this is the 8
+//!         // literal characters `foo\nbar` (backslash, `n`, not a newline), and for a
+//!         // raw string `r"foo\nbar"custom` it's identical to `$value` since there are
+//!         // no escapes to undo. `$style` is `cooked` or `raw` accordingly.
+//!         //
+//!         // `$raw_source` and `$kind` work exactly like in `integer` above, with `$kind`
+//!         // always `string` here
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw:literal @ $style:ident $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -202,8 +288,11 @@
 //!     pub mod character {
 //!         // 'x'custom
 //!         // ^^^ value - 'x'
+//!         //
+//!         // `$raw_source` and `$kind` work exactly like in `integer` above, with `$kind`
+//!         // always `character` here
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -213,8 +302,11 @@
 //!     pub mod byte_character {
 //!         // b'a'custom
 //!         //   ^ value - 97
+//!         //
+//!         // `$raw_source` and `$kind` work exactly like in `integer` above, with `$kind`
+//!         // always `byte_character` here
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -224,8 +316,11 @@
 //!     pub mod byte_string {
 //!         // b"foo_bar"custom
 //!         // ^^^^^^^^^^ value - b"foo_bar"
+//!         //
+//!         // `$raw` and `$style` work exactly like in `string` above, and `$raw_source`/`$kind`
+//!         // work exactly like in `integer` above, with `$kind` always `byte_string` here
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw:literal @ $style:ident $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -235,8 +330,11 @@
 //!     pub mod c_string {
 //!         // c"string"custom
 //!         // ^^^^^^^^^ value - c"string"
+//!         //
+//!         // `$raw_source` and `$kind` work exactly like in `integer` above, with `$kind`
+//!         // always `c_string` here
 //!         macro_rules! custom {
-//!             ($value:literal) => {
+//!             ($value:literal $raw_source:literal @ $kind:ident) => {
 //!                 // ...
 //!             }
 //!         }
@@ -260,7 +358,55 @@
 //! the macro `crate::custom_literal::int::nzusize` but *not* the actual `0nzusize`, which makes it very hard to debug these
 #![allow(clippy::needless_doctest_main)]
 
-use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use backend::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Seam between the real `proc_macro` the compiler hands us and `proc_macro2`, which mirrors its
+/// API closely enough to stand in for it. `proc_macro`'s types only exist inside an actual macro
+/// invocation, so everything below [`culit`] itself - `transform` and everything it calls - is
+/// written against this module's aliases instead of `proc_macro` directly, letting it run and be
+/// asserted on from an ordinary `#[test]` under `cfg(test)`, the same way `syn`/`quote` build
+/// against `proc_macro2` so they're usable outside a compiler invocation.
+///
+/// `culit` itself can't go through this seam: `#[proc_macro_attribute]` requires its exact
+/// signature to use `proc_macro::TokenStream`, cfg or no cfg, so [`from_real`]/[`into_real`]
+/// bridge at that one boundary - a free identity conversion in the real build, where the alias
+/// below already *is* `proc_macro::TokenStream`, and a string round-trip that only the type
+/// checker ever sees under `cfg(test)`, since `culit` is never called from a unit test.
+mod backend {
+    #[cfg(not(test))]
+    pub use proc_macro::{
+        Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
+    };
+
+    #[cfg(test)]
+    pub use proc_macro2::{
+        Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
+    };
+
+    #[cfg(not(test))]
+    pub fn from_real(ts: proc_macro::TokenStream) -> TokenStream {
+        ts
+    }
+
+    #[cfg(test)]
+    pub fn from_real(ts: proc_macro::TokenStream) -> TokenStream {
+        ts.to_string()
+            .parse()
+            .expect("`proc_macro::TokenStream` reparses fine as `proc_macro2::TokenStream`")
+    }
+
+    #[cfg(not(test))]
+    pub fn into_real(ts: TokenStream) -> proc_macro::TokenStream {
+        ts
+    }
+
+    #[cfg(test)]
+    pub fn into_real(ts: TokenStream) -> proc_macro::TokenStream {
+        ts.to_string()
+            .parse()
+            .expect("`proc_macro2::TokenStream` reparses fine as `proc_macro::TokenStream`")
+    }
+}
 
 /// Supports using custom literals such as `10km` defined at `crate::custom_literal::int::km`
 ///
@@ -274,7 +420,7 @@ use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenSt
 /// mod custom_literal {
 ///     pub mod integer {
 ///         macro_rules! km {
-///             ($value:literal) => {
+///             ($value:literal $_raw:literal $(@ $_base:ident $(@ $_sign:ident)?)? $_raw_source:literal @ $_kind:ident) => {
 ///                 $crate::Kilometers($value)
 ///             }
 ///         }
@@ -288,367 +434,885 @@ use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenSt
 /// }
 /// ```
 ///
+/// Accepts one or more `path = ...` arguments pointing at alternative modules to resolve custom
+/// literals from, e.g. `#[culit(path = some_crate::literals)]`. This lets a crate publish a
+/// reusable `custom_literal`-shaped module ("literal pack") for others to consume without
+/// redeclaring it at their own crate root. Defaults to `crate::custom_literal`.
+///
+/// With more than one `path = ...`, e.g. `#[culit(path = units::literals, path = colors::literals)]`,
+/// literals are resolved against the union of all of them, falling back from one to the next -
+/// see [`expand_custom_literal`] for how that works without any real name resolution available.
+///
+/// Accepts an optional bare `strict` argument, e.g. `#[culit(strict)]` or
+/// `#[culit(strict, path = units::literals)]`, which turns a suffix that's a near-miss typo of a
+/// real Rust suffix (e.g. `10u3`, `7i63`) into a "did you mean" error instead of silently
+/// dispatching it to `custom_literal`. Off by default, since a crate may intentionally use a
+/// short custom suffix (e.g. `id`) that happens to sit within a couple of edits of a real one -
+/// see [`suggest_suffix`].
+///
 /// For more information, see the [crate-level](crate) documentation
 #[proc_macro_attribute]
-pub fn culit(args: TokenStream, input: TokenStream) -> TokenStream {
-    if !args.is_empty() {
-        panic!("`#[culit]` does not take any arguments between `(...)`")
+pub fn culit(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = backend::from_real(args);
+
+    let mut parsed = match parse_args(args) {
+        Ok(parsed) => parsed,
+        Err(err) => return backend::into_real(err.into_iter().collect()),
+    };
+
+    if parsed.roots.is_empty() {
+        parsed.roots.push(default_root());
     }
 
-    transform(input)
+    backend::into_real(transform(
+        backend::from_real(input),
+        &parsed.roots,
+        parsed.strict,
+    ))
 }
 
-/// Recursively replaces all literals in the `TokenStream` with a call to `crate::custom_literal::$literal_type::$suffix!($ts)`
-fn transform(ts: TokenStream) -> TokenStream {
-    ts.into_iter()
-        .flat_map(|tt| {
-            // I1 = [TokenTree; 12]
-            // I2 = [TokenTree; 1]
-            // I3 = [TokenTree; 3]
-
-            match tt {
-                TokenTree::Literal(tt_lit) => {
-                    let span = tt_lit.span();
-
-                    // NOTE: `litrs::Literal::from(token_tree::Literal) exists but it unnecessarily takes by-value,
-                    // so we avoid an unnecessary clone here
-                    let lit = litrs::Literal::parse(tt_lit.to_string()).expect(concat!(
-                        "bug in the implementation of `litrs`, ",
-                        "`token_tree::Literal` -> `litrs::Literal` is infallible"
+/// The parsed arguments of `#[culit(...)]`
+struct ParsedArgs {
+    /// The `path = ...` roots, in the order they were written. Empty means "use the default
+    /// root", i.e. no `path = ...` was given at all.
+    roots: Vec<TokenStream>,
+    /// Whether the bare `strict` argument was given
+    strict: bool,
+}
+
+/// Parses zero or more comma-separated `path = some::module::path` and bare `strict` arguments,
+/// in any order. An empty `args` stream (no `#[culit(...)]` arguments at all) returns
+/// `ParsedArgs { roots: Vec::new(), strict: false }`.
+fn parse_args(args: TokenStream) -> Result<ParsedArgs, CompileError> {
+    let mut roots = Vec::new();
+    let mut strict = false;
+
+    let mut iter = args.into_iter().peekable();
+
+    while iter.peek().is_some() {
+        match iter.next() {
+            // `proc_macro::Ident` (unlike `proc_macro2::Ident`, used under `cfg(test)`) has no
+            // `PartialEq<str>`, so comparing without the `.to_string()` allocation isn't possible
+            // in the real build.
+            #[allow(clippy::cmp_owned)]
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "path" => {
+                match iter.next() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {}
+                    other => {
+                        let span = other.map_or_else(Span::call_site, |tt| tt.span());
+                        return Err(CompileError::new(span, "expected `=` after `path`"));
+                    }
+                }
+
+                let mut path_tokens = Vec::new();
+                loop {
+                    match iter.peek() {
+                        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {
+                            iter.next();
+                            break;
+                        }
+                        Some(_) => path_tokens.push(iter.next().expect("just peeked")),
+                        None => break,
+                    }
+                }
+
+                if path_tokens.is_empty() {
+                    return Err(CompileError::new(
+                        Span::call_site(),
+                        "expected a path after `path =`",
                     ));
+                }
 
-                    let suffix = lit.suffix();
+                roots.push(TokenStream::from_iter(path_tokens));
+            }
+            #[allow(clippy::cmp_owned)] // see the identical `path` arm above
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "strict" => {
+                strict = true;
 
-                    if suffix.is_empty() {
-                        // Totally skip this literal as there's no suffix
-                        return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
+                match iter.peek() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {
+                        iter.next();
+                    }
+                    Some(other) => {
+                        return Err(CompileError::new(
+                            other.span(),
+                            "expected `,` after `strict`",
+                        ));
                     }
+                    None => {}
+                }
+            }
+            Some(other) => {
+                return Err(CompileError::new(
+                    other.span(),
+                    "expected `path = ...` or `strict`, e.g. `#[culit(path = crate::custom_literal)]`",
+                ));
+            }
+            None => unreachable!("just checked with `peek`"),
+        }
+    }
 
-                    const RESERVED_MESSAGE: &str = concat!(
-                        " is not currently used ",
-                        "by rust, but it likely will be in the future",
-                        ". To avoid breakage and not compromise rust's compatibility guarantees, ",
-                        "we forbid this suffix"
-                    );
-
-                    match &lit {
-                        litrs::Literal::Integer(integer_lit) => {
-                            if INT_SUFFIXES.contains(&suffix) {
-                                return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
-                            } else if INT_SUFFIXES_RESERVED.contains(&suffix) {
-                                return AnonIter::I3(
-                                    CompileError::new(
-                                        span,
-                                        format!("suffix {suffix} {RESERVED_MESSAGE}"),
-                                    )
-                                    .into_iter(),
-                                );
-                            }
+    Ok(ParsedArgs { roots, strict })
+}
 
-                            let Some(value) = integer_lit.value::<u128>() else {
-                                return AnonIter::I3(
+/// The default lookup root, `crate::custom_literal`, used when `#[culit]` is given no
+/// `path = ...` argument.
+fn default_root() -> TokenStream {
+    TokenStream::from_iter([
+        TokenTree::Ident(Ident::new("crate", Span::call_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Ident(Ident::new("custom_literal", Span::call_site())),
+    ])
+}
+
+/// Recursively replaces all literals in the `TokenStream` with a call to `$root::$literal_type::$suffix!($ts)`
+///
+/// This is a one-token lookbehind over the stream: a `-` is only ever folded into the literal
+/// that follows it when it's unambiguously in *unary* position (start of stream, or right after
+/// another `Punct`) and the literal it's attached to would actually dispatch to `custom_literal`.
+/// A `-` after a literal, identifier, or `Group` (e.g. `)`, `]`, `}`) is binary subtraction and is
+/// left completely untouched, as is a `-` in front of a literal with no suffix or a real Rust
+/// suffix - both of those are forwarded as-is, so rustc's own unary minus must stay intact.
+fn transform(ts: TokenStream, roots: &[TokenStream], strict: bool) -> TokenStream {
+    let mut out = Vec::new();
+    let mut prev_allows_unary = true;
+    let mut tokens = ts.into_iter().peekable();
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(punct)
+                if punct.as_char() == '-'
+                    && prev_allows_unary
+                    && matches!(
+                        tokens.peek(),
+                        Some(TokenTree::Literal(next)) if has_foldable_sign(next)
+                    ) =>
+            {
+                let Some(TokenTree::Literal(tt_lit)) = tokens.next() else {
+                    unreachable!("just peeked a `TokenTree::Literal`")
+                };
+                out.extend(expand_literal(tt_lit, roots, true, strict));
+                prev_allows_unary = false;
+            }
+            TokenTree::Literal(tt_lit) => {
+                out.extend(expand_literal(tt_lit, roots, false, strict));
+                prev_allows_unary = false;
+            }
+            TokenTree::Group(group) => {
+                out.push(TokenTree::Group(Group::new(
+                    group.delimiter(),
+                    // Recurse
+                    transform(group.stream(), roots, strict),
+                )));
+                prev_allows_unary = false;
+            }
+            TokenTree::Ident(ident) => {
+                out.push(TokenTree::Ident(ident));
+                prev_allows_unary = false;
+            }
+            TokenTree::Punct(punct) => {
+                out.push(TokenTree::Punct(punct));
+                prev_allows_unary = true;
+            }
+        }
+    }
+
+    out.into_iter().collect()
+}
+
+/// Whether a leading `-` in unary position should be folded into this literal, i.e. whether it's
+/// a number that will actually dispatch to `custom_literal` rather than being forwarded untouched
+fn has_foldable_sign(tt_lit: &Literal) -> bool {
+    let Ok(lit) = litrs::Literal::parse(tt_lit.to_string()) else {
+        return false;
+    };
+
+    match &lit {
+        litrs::Literal::Integer(_) => {
+            !lit.suffix().is_empty() && !INT_SUFFIXES.contains(&lit.suffix())
+        }
+        litrs::Literal::Float(_) => {
+            !lit.suffix().is_empty() && !FLOAT_SUFFIXES.contains(&lit.suffix())
+        }
+        _ => false,
+    }
+}
+
+/// Expands a single literal `TokenTree` into its replacement tokens, folding a leading unary `-`
+/// into the Integer/Decimal expansion as an `@ neg` tag when `negative` is set. `strict`
+/// controls whether a suffix that's a near-miss of a real Rust suffix errors with a "did you
+/// mean" suggestion instead of being dispatched to `custom_literal` - see [`suggest_suffix`].
+fn expand_literal(
+    tt_lit: Literal,
+    roots: &[TokenStream],
+    negative: bool,
+    strict: bool,
+) -> impl Iterator<Item = TokenTree> {
+    // I1 = [TokenTree; 12]
+    // I2 = [TokenTree; 1]
+    // I3 = [TokenTree; 3]
+
+    let span = tt_lit.span();
+
+    // NOTE: `litrs::Literal::from(token_tree::Literal) exists but it unnecessarily takes by-value,
+    // so we avoid an unnecessary clone here
+    //
+    // `token_tree::Literal` -> `litrs::Literal` is expected to always succeed since rustc already
+    // lexed `tt_lit` as a literal, but we don't want one unparseable literal to abort expansion of
+    // the whole statement - emit a spanned error at just this literal and keep going instead
+    let lit = match litrs::Literal::parse(tt_lit.to_string()) {
+        Ok(lit) => lit,
+        Err(err) => {
+            return AnonIter::I3(
+                CompileError::new(span, format!("failed to parse literal: {err}")).into_iter(),
+            );
+        }
+    };
+
+    let suffix = lit.suffix();
+
+    if suffix.is_empty() {
+        // Totally skip this literal as there's no suffix
+        return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
+    }
+
+    // The literal's exact source text, underscores/prefix/quotes and all, minus the suffix -
+    // `litrs` only hands back a normalized reconstruction (e.g. underscore-stripped digits), so
+    // this is handed to `expand_custom_literal` separately for macros that need to reparse the
+    // verbatim input themselves (bignum, fixed-point, base-N, ...).
+    let raw_source = tt_lit.to_string();
+    let raw_source = &raw_source[..raw_source.len() - suffix.len()];
+
+    match &lit {
+        litrs::Literal::Integer(integer_lit) => {
+            // A real Rust suffix is forwarded untouched; anything else (including
+            // suffixes like `i256`/`u256` that aren't valid Rust yet) is offered to
+            // `custom_literal` so downstream crates can define extended-precision
+            // integer types under that suffix.
+            if INT_SUFFIXES.contains(&suffix) {
+                return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
+            }
+
+            // A suffix close enough to a real one is almost certainly a typo
+            // rather than an intentional custom suffix - error with a suggestion
+            // instead of silently dispatching to a `custom_literal` macro that
+            // (most likely) doesn't exist, which would otherwise surface as an
+            // opaque "cannot find macro" error far from here. Only in `strict` mode,
+            // since this is also how a short intentional custom suffix (e.g. `id`)
+            // would otherwise get flagged as a typo of a real one (e.g. `i8`).
+            if let Some(candidate) = strict
+                .then(|| suggest_suffix(suffix, INT_SUFFIXES))
+                .flatten()
+            {
+                return AnonIter::I3(
                                     CompileError::new(
                                         span,
                                         format!(
-                                            "custom integer literals are only supported for {} {}",
-                                            "integers who's absolute value does not exceed",
-                                            u128::MAX
+                                            "no integer suffix or `{}!` macro found; help: did you mean `{candidate}`?",
+                                            expected_macro_path(roots, lit_name::INTEGER, suffix)
                                         ),
                                     )
                                     .into_iter(),
                                 );
-                            };
-
-                            let value =
-                                TokenTree::Literal(Literal::u128_unsuffixed(value)).with_span(span);
-
-                            // Token on the outside
-                            //
-                            // + crate::custom_literal::int::$suffix!($value)
-                            //
-                            // ^ current_tt (can be ANY token)
-                            AnonIter::I1(
-                                expand_custom_literal(
-                                    lit_name::INTEGER,
-                                    suffix,
-                                    span,
-                                    TokenStream::from_iter([value]),
-                                )
-                                .into_iter(),
-                            )
-                        }
-                        // crate::custom_literal::str::$suffix!($value)
-                        litrs::Literal::String(string_lit) => AnonIter::I1(
-                            expand_custom_literal(
-                                lit_name::STRING,
-                                suffix,
-                                span,
-                                TokenStream::from(
-                                    // $value
-                                    TokenTree::Literal(Literal::string(string_lit.value()))
-                                        .with_span(span),
-                                ),
-                            )
-                            .into_iter(),
+            }
+
+            let Some(value) = integer_lit.value::<u128>() else {
+                return AnonIter::I3(
+                    CompileError::new(
+                        span,
+                        format!(
+                            "custom integer literals are only supported for {} {}",
+                            "integers who's absolute value does not exceed",
+                            u128::MAX
                         ),
-                        litrs::Literal::Float(float_lit) => {
-                            if FLOAT_SUFFIXES.contains(&suffix) {
-                                return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
-                            } else if FLOAT_SUFFIXES_RESERVED.contains(&suffix) {
-                                return AnonIter::I3(
-                                    CompileError::new(
-                                        span,
-                                        format!("suffix {suffix} {RESERVED_MESSAGE}"),
-                                    )
-                                    .into_iter(),
-                                );
-                            }
-
-                            let Ok(integral) = float_lit
-                                .integer_part()
-                                .split('_')
-                                .collect::<String>()
-                                .parse::<u128>()
-                            else {
-                                return AnonIter::I3(
-                                    CompileError::new(
-                                        span,
-                                        format!(
-                                            "custom float literals are only supported for {} {} {}",
-                                            "floats that who's integral part (before the `.`)",
-                                            "does not exceed",
-                                            u128::MAX
-                                        ),
-                                    )
-                                    .into_iter(),
-                                );
-                            };
-
-                            let Ok(fractional) = float_lit
-                                .fractional_part()
-                                .map(|it| it.split('_').collect::<String>().parse::<u128>())
-                                .unwrap_or(Ok(0))
-                            else {
-                                return AnonIter::I3(
+                    )
+                    .with_note(format!(
+                        "expected a macro at `{}`",
+                        expected_macro_path(roots, lit_name::INTEGER, suffix)
+                    ))
+                    .into_iter(),
+                );
+            };
+
+            // Sign-free, underscore-stripped digit text exactly as written by the
+            // user, e.g. `0xDEAD_BEEF` -> `"DEADBEEF"`. This lets a macro build
+            // values wider than `u128` (bignum, fixed-point, ...) by folding
+            // `acc = acc * base + digit` over the raw text instead of being
+            // capped at whatever already fit in `value`.
+            let raw_digits: String = integer_lit
+                .raw_main_part()
+                .chars()
+                .filter(|c| *c != '_')
+                .collect();
+
+            // Which radix the user wrote the literal in, so a macro can e.g.
+            // reject non-hex color literals or format differently per base.
+            let base = match integer_lit.base() {
+                litrs::IntegerBase::Binary => "bin",
+                litrs::IntegerBase::Octal => "oct",
+                litrs::IntegerBase::Decimal => "dec",
+                litrs::IntegerBase::Hexadecimal => "hex",
+            };
+
+            let value = TokenTree::Literal(Literal::u128_unsuffixed(value)).with_span(span);
+            let raw_digits = TokenTree::Literal(Literal::string(&raw_digits)).with_span(span);
+            let base = [
+                TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+                TokenTree::Ident(Ident::new(base, span)),
+            ];
+
+            // Only present when a leading unary `-` was folded into this literal;
+            // omitted (rather than e.g. always emitting `@ pos`/`@ neg`) so macros
+            // written before sign-folding existed keep matching unchanged literals
+            let sign = negative.then(|| {
+                [
+                    TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+                    TokenTree::Ident(Ident::new("neg", span)),
+                ]
+            });
+
+            // Token on the outside
+            //
+            // + crate::custom_literal::int::$suffix!($value $raw_digits @ $base @ neg)
+            //
+            // ^ current_tt (can be ANY token)
+            AnonIter::I1(
+                expand_custom_literal(
+                    roots,
+                    lit_name::INTEGER,
+                    suffix,
+                    span,
+                    TokenStream::from_iter(
+                        [value, raw_digits]
+                            .into_iter()
+                            .chain(base)
+                            .chain(sign.into_iter().flatten()),
+                    ),
+                    raw_source,
+                ),
+            )
+        }
+        // crate::custom_literal::str::$suffix!($value $raw @ cooked/raw)
+        litrs::Literal::String(string_lit) => {
+            // Pre-unescape source text: for `"\d+"` this is the 3 literal characters
+            // `\`, `d`, `+` rather than `$value`'s already-interpreted content, so a
+            // macro for e.g. embedded regexes or paths can work with exactly what the
+            // user wrote. Raw strings have no escapes to undo, so their `$value` is
+            // already this text; cooked strings need it carved out of the source by hand
+            // since `litrs` only exposes the unescaped value for those.
+            let raw_main = if string_lit.is_raw_string() {
+                string_lit.value().to_owned()
+            } else {
+                let raw_input = string_lit.raw_input();
+                raw_input[1..raw_input.len() - suffix.len() - 1].to_owned()
+            };
+
+            let style = if string_lit.is_raw_string() {
+                "raw"
+            } else {
+                "cooked"
+            };
+
+            AnonIter::I1(
+                expand_custom_literal(
+                    roots,
+                    lit_name::STRING,
+                    suffix,
+                    span,
+                    TokenStream::from_iter([
+                        // $value
+                        TokenTree::Literal(Literal::string(string_lit.value())).with_span(span),
+                        // $raw
+                        TokenTree::Literal(Literal::string(&raw_main)).with_span(span),
+                        // @ $style
+                        TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+                        TokenTree::Ident(Ident::new(style, span)),
+                    ]),
+                    raw_source,
+                ),
+            )
+        }
+        litrs::Literal::Float(float_lit) => {
+            // Same reasoning as the integer arm above: real Rust suffixes
+            // (including `f16`/`f128`) are forwarded untouched.
+            if FLOAT_SUFFIXES.contains(&suffix) {
+                return AnonIter::I2([TokenTree::Literal(tt_lit)].into_iter());
+            }
+
+            // See the identical check in the integer arm above.
+            if let Some(candidate) = strict
+                .then(|| suggest_suffix(suffix, FLOAT_SUFFIXES))
+                .flatten()
+            {
+                return AnonIter::I3(
                                     CompileError::new(
                                         span,
                                         format!(
-                                            concat!(
-                                                "custom float literals are only supported for ",
-                                                "floats that who's fractional ",
-                                                "part (after the `.`) does not exceed {}"
-                                            ),
-                                            u128::MAX
+                                            "no float suffix or `{}!` macro found; help: did you mean `{candidate}`?",
+                                            expected_macro_path(roots, lit_name::DECIMAL, suffix)
                                         ),
                                     )
                                     .into_iter(),
                                 );
-                            };
-
-                            let (is_negative, exponent) = match float_lit.exponent_part() {
-                                // No exponent -> n.pow(1) == n
-                                "" => (false, 1),
-                                // Has any other exponent
-                                exp => {
-                                    let first_part =
-                                        exp.get(1..).expect("first letter is `e` or `E`");
-
-                                    let without_minus = first_part.strip_prefix('-');
-                                    let is_negative = without_minus.is_some();
-                                    let without_minus = without_minus.unwrap_or(first_part);
-
-                                    // Remove '+' at the beginning
-                                    let Ok(exp) = without_minus
-                                        .strip_prefix('+')
-                                        .unwrap_or(without_minus)
-                                        .split('_')
-                                        .collect::<String>()
-                                        .parse::<u128>()
-                                    else {
-                                        return AnonIter::I3(
-                                            CompileError::new(
-                                                span,
-                                                format!(
-                                            "custom float literals are only supported for {} {}",
-                                            "floats that who's exponent does not exceed",
-                                            u128::MAX
-                                        ),
-                                            )
-                                            .into_iter(),
-                                        );
-                                    };
-                                    (is_negative, exp)
-                                }
-                            };
-
-                            // Token for the sign of the exponent
-                            //
-                            // 1e+3 is None
-                            // 1e3 is None
-                            // 1e-3 is Some(TokenTree)
-                            let exponent_sign = is_negative
-                                .then(|| TokenTree::Punct(Punct::new('-', Spacing::Joint)));
-
-                            // Whatever token on the outside
-                            //
-                            // + crate::custom_literal::decimal::$suffix!($integral $fractional $exponen)
-                            //
-                            // ^ current_tt (can be ANY token)
-                            AnonIter::I1(
-                                expand_custom_literal(
-                                    lit_name::DECIMAL,
-                                    suffix,
-                                    span,
-                                    TokenStream::from_iter(
-                                        [
-                                            TokenTree::Literal(Literal::u128_unsuffixed(integral))
-                                                .with_span(span),
-                                            TokenTree::Literal(Literal::u128_unsuffixed(
-                                                fractional,
-                                            ))
-                                            .with_span(span),
-                                        ]
-                                        .into_iter()
-                                        .chain(exponent_sign)
-                                        .chain([
-                                            TokenTree::Literal(Literal::u128_unsuffixed(exponent))
-                                                .with_span(span),
-                                        ]),
-                                    ),
-                                )
-                                .into_iter(),
-                            )
-                        }
-                        // crate::custom_literal::char::$suffix!($value)
-                        litrs::Literal::Char(char_lit) => AnonIter::I1(
-                            expand_custom_literal(
-                                lit_name::CHARACTER,
-                                suffix,
-                                span,
-                                TokenStream::from(
-                                    // $value
-                                    TokenTree::Literal(Literal::character(char_lit.value()))
-                                        .with_span(span),
-                                ),
-                            )
-                            .into_iter(),
+            }
+
+            let Ok(integral) = float_lit
+                .integer_part()
+                .split('_')
+                .collect::<String>()
+                .parse::<u128>()
+            else {
+                return AnonIter::I3(
+                    CompileError::new(
+                        span,
+                        format!(
+                            "custom float literals are only supported for {} {} {}",
+                            "floats that who's integral part (before the `.`)",
+                            "does not exceed",
+                            u128::MAX
+                        ),
+                    )
+                    .with_note(format!(
+                        "expected a macro at `{}`",
+                        expected_macro_path(roots, lit_name::DECIMAL, suffix)
+                    ))
+                    .into_iter(),
+                );
+            };
+
+            // Number of fractional digits as written, underscores aside, e.g.
+            // `1.007` -> `3`. `$fractional` alone can't distinguish `1.007` from
+            // `1.7` since leading zeros are lost once parsed into a `u128` -
+            // this lets a macro reconstruct the exact value as
+            // `integral + fractional / 10^frac_len`.
+            let frac_len = float_lit
+                .fractional_part()
+                .map(|it| it.chars().filter(|c| *c != '_').count())
+                .unwrap_or(0);
+
+            let Ok(fractional) = float_lit
+                .fractional_part()
+                .map(|it| it.split('_').collect::<String>().parse::<u128>())
+                .unwrap_or(Ok(0))
+            else {
+                return AnonIter::I3(
+                    CompileError::new(
+                        span,
+                        format!(
+                            concat!(
+                                "custom float literals are only supported for ",
+                                "floats that who's fractional ",
+                                "part (after the `.`) does not exceed {}"
+                            ),
+                            u128::MAX
                         ),
-                        // crate::custom_literal::byte_char::$suffix!($value)
-                        litrs::Literal::Byte(byte_lit) => AnonIter::I1(
-                            expand_custom_literal(
-                                lit_name::BYTE_CHARACTER,
-                                suffix,
+                    )
+                    .with_note(format!(
+                        "expected a macro at `{}`",
+                        expected_macro_path(roots, lit_name::DECIMAL, suffix)
+                    ))
+                    .into_iter(),
+                );
+            };
+
+            let (is_negative, exponent) = match float_lit.exponent_part() {
+                // No exponent -> n.pow(1) == n
+                "" => (false, 1),
+                // Has any other exponent
+                exp => {
+                    let first_part = exp.get(1..).expect("first letter is `e` or `E`");
+
+                    let without_minus = first_part.strip_prefix('-');
+                    let is_negative = without_minus.is_some();
+                    let without_minus = without_minus.unwrap_or(first_part);
+
+                    // Remove '+' at the beginning
+                    let Ok(exp) = without_minus
+                        .strip_prefix('+')
+                        .unwrap_or(without_minus)
+                        .split('_')
+                        .collect::<String>()
+                        .parse::<u128>()
+                    else {
+                        return AnonIter::I3(
+                            CompileError::new(
                                 span,
-                                TokenStream::from(
-                                    // $value
-                                    TokenTree::Literal(Literal::u8_unsuffixed(byte_lit.value()))
-                                        .with_span(span),
+                                format!(
+                                    "custom float literals are only supported for {} {}",
+                                    "floats that who's exponent does not exceed",
+                                    u128::MAX
                                 ),
                             )
-                            .into_iter(),
-                        ),
-                        // crate::custom_literal::byte_str::$suffix!($value)
-                        litrs::Literal::ByteString(byte_string_lit) => {
-                            AnonIter::I1(
-                                expand_custom_literal(
-                                    lit_name::BYTE_STRING,
-                                    suffix,
-                                    span,
-                                    TokenStream::from(
-                                        // $value
-                                        TokenTree::Literal(Literal::byte_string(
-                                            byte_string_lit.value(),
-                                        ))
-                                        .with_span(span),
-                                    ),
-                                )
-                                .into_iter(),
-                            )
-                        }
-                        #[cfg(not(has_c_string))]
-                        litrs::Literal::CString(_cstring_lit) => {
-                            return AnonIter::I2(CompileError::new(
-                                tt_lit.span(),
-                                concat!(
-                                    "custom c-string literal with suffix ",
-                                    "is only supported on Rust version >=1.79"
-                                ),
+                            .with_note(format!(
+                                "expected a macro at `{}`",
+                                expected_macro_path(roots, lit_name::DECIMAL, suffix)
                             ))
-                            .into_iter()
-                            .collect();
-                        }
-                        // crate::custom_literal::c_str::$suffix!($value)
-                        #[cfg(has_c_string)]
-                        // lints for usage of "Literal::c_string" but we explicitly
-                        // check that we are on a version that allows it
-                        #[cfg_attr(has_c_string, allow(clippy::incompatible_msrv))]
-                        litrs::Literal::CString(cstring_lit) => {
-                            AnonIter::I1(
-                                expand_custom_literal(
-                                    lit_name::C_STRING,
-                                    suffix,
-                                    span,
-                                    TokenStream::from(
-                                        // $value
-                                        TokenTree::Literal(Literal::c_string(cstring_lit.value()))
-                                            .with_span(span),
-                                    ),
-                                )
-                                .into_iter(),
-                            )
-                        }
-                        litrs::Literal::Bool(_bool_lit) => {
-                            unreachable!(
-                                "booleans aren't `TokenTree::Literal`, they're `TokenTree::Ident`"
-                            )
-                        }
-                    }
-                }
-                TokenTree::Group(group) => {
-                    AnonIter::I2(
-                        [TokenTree::Group(Group::new(
-                            group.delimiter(),
-                            // Recurse
-                            transform(group.stream()),
-                        ))]
-                        .into_iter(),
-                    )
+                            .into_iter(),
+                        );
+                    };
+                    (is_negative, exp)
                 }
-                next_tt => AnonIter::I2([next_tt].into_iter()),
-            }
-        })
-        .collect()
+            };
+
+            // Token for the sign of the exponent
+            //
+            // 1e+3 is None
+            // 1e3 is None
+            // 1e-3 is Some(TokenTree)
+            let exponent_sign =
+                is_negative.then(|| TokenTree::Punct(Punct::new('-', Spacing::Joint)));
+
+            // Raw, underscore-stripped mantissa text exactly as written (leading
+            // zeros preserved, no overflow), so a macro can parse exact-decimal or
+            // fixed-point suffixes instead of going through a lossy `u128` split.
+            let raw_mantissa = format!(
+                "{}.{}",
+                float_lit
+                    .integer_part()
+                    .chars()
+                    .filter(|c| *c != '_')
+                    .collect::<String>(),
+                float_lit
+                    .fractional_part()
+                    .unwrap_or("0")
+                    .chars()
+                    .filter(|c| *c != '_')
+                    .collect::<String>(),
+            );
+            let raw_mantissa = TokenTree::Literal(Literal::string(&raw_mantissa)).with_span(span);
+
+            // Only present when a leading unary `-` was folded into this literal;
+            // see the identical reasoning in the integer arm above.
+            let sign = negative.then(|| {
+                [
+                    TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+                    TokenTree::Ident(Ident::new("neg", span)),
+                ]
+            });
+
+            // Whatever token on the outside
+            //
+            // + crate::custom_literal::decimal::$suffix!($integral $fractional $frac_len $exponent $raw_mantissa @ neg)
+            //
+            // ^ current_tt (can be ANY token)
+            AnonIter::I1(
+                expand_custom_literal(
+                    roots,
+                    lit_name::DECIMAL,
+                    suffix,
+                    span,
+                    TokenStream::from_iter(
+                        [
+                            TokenTree::Literal(Literal::u128_unsuffixed(integral)).with_span(span),
+                            TokenTree::Literal(Literal::u128_unsuffixed(fractional))
+                                .with_span(span),
+                            TokenTree::Literal(Literal::usize_unsuffixed(frac_len)).with_span(span),
+                        ]
+                        .into_iter()
+                        .chain(exponent_sign)
+                        .chain([
+                            TokenTree::Literal(Literal::u128_unsuffixed(exponent)).with_span(span)
+                        ])
+                        .chain([raw_mantissa])
+                        .chain(sign.into_iter().flatten()),
+                    ),
+                    raw_source,
+                ),
+            )
+        }
+        // crate::custom_literal::char::$suffix!($value)
+        litrs::Literal::Char(char_lit) => AnonIter::I1(
+            expand_custom_literal(
+                roots,
+                lit_name::CHARACTER,
+                suffix,
+                span,
+                TokenStream::from(
+                    // $value
+                    TokenTree::Literal(Literal::character(char_lit.value())).with_span(span),
+                ),
+                raw_source,
+            ),
+        ),
+        // crate::custom_literal::byte_char::$suffix!($value)
+        litrs::Literal::Byte(byte_lit) => AnonIter::I1(
+            expand_custom_literal(
+                roots,
+                lit_name::BYTE_CHARACTER,
+                suffix,
+                span,
+                TokenStream::from(
+                    // $value
+                    TokenTree::Literal(Literal::u8_unsuffixed(byte_lit.value())).with_span(span),
+                ),
+                raw_source,
+            ),
+        ),
+        // crate::custom_literal::byte_str::$suffix!($value $raw @ cooked/raw)
+        litrs::Literal::ByteString(byte_string_lit) => {
+            // Same reasoning as the `String` arm above: a raw byte string's `value()`
+            // is already the pre-unescape bytes, a cooked one needs them carved out
+            // of the source by hand (`b"` / `"` around the main part, no hashes)
+            let raw_main: Vec<u8> = if byte_string_lit.is_raw_byte_string() {
+                byte_string_lit.value().to_owned()
+            } else {
+                let raw_input = byte_string_lit.raw_input();
+                raw_input.as_bytes()[2..raw_input.len() - suffix.len() - 1].to_owned()
+            };
+
+            let style = if byte_string_lit.is_raw_byte_string() {
+                "raw"
+            } else {
+                "cooked"
+            };
+
+            AnonIter::I1(
+                expand_custom_literal(
+                    roots,
+                    lit_name::BYTE_STRING,
+                    suffix,
+                    span,
+                    TokenStream::from_iter([
+                        // $value
+                        TokenTree::Literal(Literal::byte_string(byte_string_lit.value()))
+                            .with_span(span),
+                        // $raw
+                        TokenTree::Literal(Literal::byte_string(&raw_main)).with_span(span),
+                        // @ $style
+                        TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+                        TokenTree::Ident(Ident::new(style, span)),
+                    ]),
+                    raw_source,
+                ),
+            )
+        }
+        #[cfg(not(has_c_string))]
+        litrs::Literal::CString(_cstring_lit) => {
+            return AnonIter::I2(CompileError::new(
+                tt_lit.span(),
+                concat!(
+                    "custom c-string literal with suffix ",
+                    "is only supported on Rust version >=1.79"
+                ),
+            ))
+            .into_iter()
+            .collect();
+        }
+        // crate::custom_literal::c_str::$suffix!($value)
+        #[cfg(has_c_string)]
+        // lints for usage of "Literal::c_string" but we explicitly
+        // check that we are on a version that allows it
+        #[cfg_attr(has_c_string, allow(clippy::incompatible_msrv))]
+        litrs::Literal::CString(cstring_lit) => {
+            AnonIter::I1(
+                expand_custom_literal(
+                    roots,
+                    lit_name::C_STRING,
+                    suffix,
+                    span,
+                    TokenStream::from(
+                        // $value
+                        TokenTree::Literal(Literal::c_string(cstring_lit.value())).with_span(span),
+                    ),
+                    raw_source,
+                ),
+            )
+        }
+        litrs::Literal::Bool(_bool_lit) => {
+            unreachable!("booleans aren't `TokenTree::Literal`, they're `TokenTree::Ident`")
+        }
+    }
+}
+
+/// Renders the macro invocation a literal with this `literal_type`/`suffix` would have dispatched
+/// to, e.g. `crate::custom_literal::integer::km!` - used as a "note: ..." breadcrumb on errors
+/// that already know this much (overflow, malformed digits) but had to bail before actually
+/// reaching [`expand_custom_literal`].
+fn expected_macro_path(roots: &[TokenStream], literal_type: &str, suffix: &str) -> String {
+    roots
+        .iter()
+        .map(|root| format!("{root}::{literal_type}::{suffix}!"))
+        .collect::<Vec<_>>()
+        .join(" or ")
 }
 
-/// Expands a custom literal into `crate::custom_literal::$literal_type::$suffix!($ts)`
+/// Expands a custom literal into a call to `$literal_type::$suffix!($ts)`, resolved from the
+/// single root in `roots` when there's only one, e.g. `crate::custom_literal::integer::km!(100)`.
+///
+/// When `#[culit]` was given more than one `path = ...`, there's no way for a token-level macro
+/// to check ahead of time which root actually defines `$suffix!` - so instead, every root's
+/// `$literal_type` module is glob-imported into one scratch module and `$suffix!` is resolved
+/// against *that*. As long as only one root defines a macro with this name for this literal
+/// kind, the glob merge is unambiguous and resolves to whichever root actually has it; two roots
+/// defining the same suffix for the same kind is an ambiguous-glob-import error, same as it would
+/// be for a plain `use a::*; use b::*;` with an overlapping name.
+///
+/// The scratch module sits one level deeper than the literal itself, so a root written relative
+/// to wherever `#[culit]` was invoked (anything but `crate::...`) gets a `super::` hop prepended
+/// to still find it - except `self::...`, which has its leading `self::` replaced by `super::`
+/// outright, since `super::self::...` is a syntax error (`self` is only valid in path start
+/// position). The glob imports are `pub(crate)` rather than `pub` since the roots' suffix macros
+/// are typically `pub(crate)` themselves.
+///
+/// Unlike the single-root path, which splices the bare `root::literal_type::suffix!(...)` tokens
+/// directly where the literal was, the multi-root path wraps that call in a
+/// `{ mod __culit_literals { ... }; __culit_literals::suffix!(...) }` block - a block is only
+/// valid in expression position, so a literal resolved through more than one `path = ...` can
+/// never appear in pattern position (e.g. `match x { 10km => ... }` fails with "expected pattern,
+/// found block" or similar). There's no block wrapper to avoid this for a single root, but that
+/// doesn't by itself guarantee a single-root literal is accepted everywhere an ordinary literal
+/// is - pattern position imposes its own restrictions on macro calls that are out of scope here.
+///
+/// Every call also gets `raw_source` (the literal's exact source text, minus its suffix) and a
+/// trailing `@ $literal_type` tag appended onto `ts`, regardless of literal kind - a macro that
+/// doesn't need them can simply ignore both via a trailing `$_raw_source:literal @ $_kind:ident`
+/// in its own pattern.
 fn expand_custom_literal(
+    roots: &[TokenStream],
     literal_type: &str,
     suffix: &str,
     span: Span,
     ts: TokenStream,
-) -> [TokenTree; 12] {
-    [
-        TokenTree::Ident(Ident::new("crate", Span::call_site())),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new("custom_literal", Span::call_site())),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Ident(Ident::new(literal_type, Span::call_site())),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+    raw_source: &str,
+) -> impl Iterator<Item = TokenTree> {
+    let ts = TokenStream::from_iter(ts.into_iter().chain([
+        TokenTree::Literal(Literal::string(raw_source)).with_span(span),
+        TokenTree::Punct(Punct::new('@', Spacing::Alone)).with_span(span),
+        TokenTree::Ident(Ident::new(literal_type, span)),
+    ]));
+
+    let suffix_call = [
         TokenTree::Ident(Ident::new(suffix, span)),
         TokenTree::Punct(Punct::new('!', Spacing::Joint)).with_span(span),
-        TokenTree::Group(Group::new(proc_macro::Delimiter::Parenthesis, ts)).with_span(span),
-    ]
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, ts)).with_span(span),
+    ];
+
+    let [root] = roots else {
+        let glob_imports = roots.iter().flat_map(|root| {
+            // The scratch module below nests the merged imports one level deeper than the
+            // literal's original position, so any root resolved relative to that position -
+            // a bare path (e.g. `units::literals`), `self::...`, or `super::...` - needs a
+            // `super::` hop to still resolve from in there. Only `crate::...` is already
+            // absolute and must stay as the user/`default_root` wrote it.
+            let mut root_tokens = root.clone().into_iter();
+            // `proc_macro::Ident` (unlike `proc_macro2::Ident`, used under `cfg(test)`) has no
+            // `PartialEq<str>`, so comparing without the `.to_string()` allocation isn't possible
+            // in the real build.
+            #[allow(clippy::cmp_owned)]
+            let is_crate = matches!(
+                root.clone().into_iter().next(),
+                Some(TokenTree::Ident(ident)) if ident.to_string() == "crate"
+            );
+            #[allow(clippy::cmp_owned)] // see `is_crate` above
+            let is_self = matches!(
+                root.clone().into_iter().next(),
+                Some(TokenTree::Ident(ident)) if ident.to_string() == "self"
+            );
+
+            // `self::foo` can't just be prefixed with `super::` - `self` is only valid in path
+            // start position, so `super::self::foo` is a syntax error. Instead, the leading
+            // `self ::` is dropped and `super::` takes its place, same as it would for a bare
+            // `foo` path with no explicit leading segment.
+            if is_self {
+                root_tokens.next(); // `self`
+                root_tokens.next(); // first `:`
+                root_tokens.next(); // second `:`
+            }
+            let root_tail = TokenStream::from_iter(root_tokens);
+
+            let super_prefix = (!is_crate).then(|| {
+                [
+                    TokenTree::Ident(Ident::new("super", Span::call_site())),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                ]
+            });
+
+            // `pub(crate)`, not `pub`: the imported items may only be `pub(crate)` themselves
+            // (e.g. the `macro_rules!` suffixes in this crate's own tests), and re-exporting
+            // them any wider than that is a privacy error
+            [
+                TokenTree::Ident(Ident::new("pub", Span::call_site())),
+                TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    TokenStream::from_iter([TokenTree::Ident(Ident::new(
+                        "crate",
+                        Span::call_site(),
+                    ))]),
+                )),
+                TokenTree::Ident(Ident::new("use", Span::call_site())),
+            ]
+            .into_iter()
+            .chain(super_prefix.into_iter().flatten())
+            .chain(root_tail)
+            .chain([
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new(literal_type, Span::call_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+            ])
+        });
+
+        let scratch_module = [
+            TokenTree::Ident(Ident::new("mod", Span::call_site())),
+            TokenTree::Ident(Ident::new("__culit_literals", Span::call_site())),
+            TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                TokenStream::from_iter(glob_imports),
+            )),
+        ];
+
+        let body = scratch_module.into_iter().chain([
+            TokenTree::Ident(Ident::new("__culit_literals", Span::call_site())),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        ]);
+
+        return TokenStream::from_iter([TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            TokenStream::from_iter(body.chain(suffix_call)),
+        ))
+        .with_span(span)])
+        .into_iter();
+    };
+
+    TokenStream::from_iter(
+        root.clone()
+            .into_iter()
+            .chain([
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Ident(Ident::new(literal_type, Span::call_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            ])
+            .chain(suffix_call),
+    )
+    .into_iter()
 }
 
 /// `.into_iter()` generates `compile_error!($message)` at `$span`
+///
+/// Every malformed-literal site in a `transform`d statement gets its own `CompileError` inserted
+/// right where that literal was, independently of every other one - so a file with several bad
+/// literals already shows every one of them in a single `cargo build`, not one recompile at a
+/// time, with no separate collector needed: each `compile_error!` is its own complete expression,
+/// so rustc reports all of them from the one expansion.
 struct CompileError {
     /// Where the compile error is generates
     pub span: Span,
     /// Message of the compile error
     pub message: String,
+    /// Trailing "note: ..." line, e.g. pointing at the `custom_literal` macro invocation this
+    /// literal would have dispatched to had it been valid
+    pub note: Option<String>,
 }
 
 impl CompileError {
@@ -657,8 +1321,18 @@ impl CompileError {
         Self {
             span,
             message: message.as_ref().to_string(),
+            note: None,
         }
     }
+
+    /// Attaches a trailing note to the error message. Stable `compile_error!` can only ever
+    /// underline the one span it's invoked at, so there's no way to attach this as an actual
+    /// secondary labelled span the way `proc_macro::Diagnostic::span_note` can on nightly -
+    /// folding it into the same message is the closest equivalent on stable.
+    pub fn with_note(mut self, note: impl AsRef<str>) -> Self {
+        self.note = Some(note.as_ref().to_string());
+        self
+    }
 }
 
 impl IntoIterator for CompileError {
@@ -666,12 +1340,17 @@ impl IntoIterator for CompileError {
     type IntoIter = std::array::IntoIter<Self::Item, 3>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let message = match &self.note {
+            Some(note) => format!("{}\n\nnote: {note}", self.message),
+            None => self.message,
+        };
+
         [
             TokenTree::Ident(Ident::new("compile_error", self.span)),
             TokenTree::Punct(Punct::new('!', Spacing::Alone)).with_span(self.span),
             TokenTree::Group(Group::new(Delimiter::Brace, {
                 TokenStream::from(
-                    TokenTree::Literal(Literal::string(&self.message)).with_span(self.span),
+                    TokenTree::Literal(Literal::string(&message)).with_span(self.span),
                 )
             }))
             .with_span(self.span),
@@ -708,21 +1387,67 @@ mod lit_name {
 
 // NOTE: Adding or modifying the constants is a BREAKING CHANGE
 
-/// List of all integer suffixes currently accepted by Rust
+/// List of all integer suffixes currently accepted by Rust.
+///
+/// Anything not in this list (e.g. `i256`/`u256`, which Rust does not define) is treated
+/// as a custom literal suffix and dispatched to `custom_literal`.
 #[rustfmt::skip]
 const INT_SUFFIXES: &[&str] = &[
     "i8", "i16", "i32", "i64", "i128", "isize",
     "u8", "u16", "u32", "u64", "u128", "usize",
 ];
 
-/// Integer suffixes currently not accepted, but could be in the future
-const INT_SUFFIXES_RESERVED: &[&str] = &["i256", "u256"];
+/// List of all float suffixes currently accepted by Rust.
+///
+/// Anything not in this list is treated as a custom literal suffix and dispatched to
+/// `custom_literal`.
+const FLOAT_SUFFIXES: &[&str] = &["f32", "f64", "f16", "f128"];
+
+/// Finds the closest candidate to `suffix`, if any is within 1 edit for a suffix of up to 4
+/// characters, or 2 edits for anything longer - close enough that it's likely a typo of
+/// `candidate` rather than an intentionally different custom suffix. Only consulted in `strict`
+/// mode (see [`culit`]), since even this tight a threshold still catches deliberately short
+/// custom suffixes such as `id` (1 edit from `i8`).
+///
+/// `candidates` is always [`INT_SUFFIXES`]/[`FLOAT_SUFFIXES`] - the fixed list of suffixes Rust
+/// itself defines - rather than whatever suffix macros the user's own `custom_literal::integer`/
+/// `decimal` module happens to declare. `expand_literal` only ever sees the tokens of the single
+/// item `#[culit]` was attached to, with no resolved view of other modules elsewhere in the
+/// crate, so there's no general way for it to enumerate what suffix macros actually exist at a
+/// given root to suggest one of *those* instead - that would need real name resolution, which a
+/// stable token-level proc-macro attribute doesn't have. A near-miss of a *real* Rust suffix is
+/// the one case this can still catch reliably, since that list is fixed and known up front.
+fn suggest_suffix<'a>(suffix: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let threshold = if suffix.chars().count() <= 4 { 1 } else { 2 };
+
+    candidates
+        .iter()
+        .map(|&candidate| (candidate, levenshtein(suffix, candidate)))
+        .filter(|&(_, distance)| distance <= threshold)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Standard two-row dynamic-programming edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
 
-/// Float suffixes currently accepted by Rust
-const FLOAT_SUFFIXES: &[&str] = &["f32", "f64"];
+    for (i, a_ch) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, &b_ch) in b.iter().enumerate() {
+            let substitution_cost = usize::from(a_ch != b_ch);
+            cur[j + 1] = (prev[j + 1] + 1)
+                .min(cur[j] + 1)
+                .min(prev[j] + substitution_cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
 
-/// Float suffixes currently not accepted, but could be in the future
-const FLOAT_SUFFIXES_RESERVED: &[&str] = &["f16", "f128"];
+    prev[b.len()]
+}
 
 /// Wraps many `impl Iterator` which may be of different types
 ///
@@ -754,3 +1479,177 @@ impl<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(src: &str, strict: bool) -> String {
+        transform(src.parse().unwrap(), &[default_root()], strict).to_string()
+    }
+
+    #[test]
+    fn literal_without_suffix_is_untouched() {
+        assert_eq!(expand("100", false), "100");
+    }
+
+    #[test]
+    fn integer_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand("100km", false),
+            r#"crate ::custom_literal ::integer ::km !(100 "100" @ dec "100" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn leading_unary_minus_folds_into_the_literal() {
+        assert_eq!(
+            expand("-100km", false),
+            r#"crate ::custom_literal ::integer ::km !(100 "100" @ dec @ neg "100" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn binary_minus_before_a_custom_literal_is_left_alone() {
+        assert_eq!(
+            expand("a - 100km", false),
+            r#"a - crate ::custom_literal ::integer ::km !(100 "100" @ dec "100" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn real_rust_suffix_is_forwarded_untouched() {
+        assert_eq!(expand("100i32", false), "100i32");
+    }
+
+    #[test]
+    fn oversized_integer_literal_errors_with_a_note_pointing_at_the_expected_macro() {
+        let out = expand("999999999999999999999999999999999999999999999999km", false);
+        assert!(out.contains("compile_error"), "{out}");
+        assert!(out.contains("does not exceed"), "{out}");
+        assert!(
+            out.contains("note: expected a macro at `crate ::custom_literal::integer::km!`"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn recurses_into_nested_groups() {
+        assert_eq!(
+            expand("(100km)", false),
+            r#"(crate ::custom_literal ::integer ::km !(100 "100" @ dec "100" @ integer))"#
+        );
+    }
+
+    #[test]
+    fn near_miss_suffix_dispatches_to_custom_literal_by_default() {
+        assert_eq!(
+            expand("10u3", false),
+            r#"crate ::custom_literal ::integer ::u3 !(10 "10" @ dec "10" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn strict_mode_suggests_the_near_miss_real_suffix() {
+        let out = expand("10u3", true);
+        assert!(out.contains("compile_error"), "{out}");
+        assert!(out.contains("did you mean `u8`"), "{out}");
+    }
+
+    #[test]
+    fn strict_mode_still_lets_a_short_custom_suffix_through_when_far_enough_from_any_real_one() {
+        // `km` isn't within 1 edit of any `INT_SUFFIXES` entry, so it's never treated as a typo
+        assert_eq!(
+            expand("100km", true),
+            r#"crate ::custom_literal ::integer ::km !(100 "100" @ dec "100" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn non_integer_suffix_receives_the_exact_raw_source_text_and_kind() {
+        assert_eq!(
+            expand("0x1_0_0km", false),
+            r#"crate ::custom_literal ::integer ::km !(256 "100" @ hex "0x1_0_0" @ integer)"#
+        );
+    }
+
+    #[test]
+    fn decimal_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand("70.8e7feet", false),
+            r#"crate ::custom_literal ::decimal ::feet !(70 8 1 7 "70.8" "70.8e7" @ decimal)"#
+        );
+    }
+
+    #[test]
+    fn string_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand(r#""foo"bar"#, false),
+            r#"crate ::custom_literal ::string ::bar !("foo" "foo" @ cooked "\"foo\"" @ string)"#
+        );
+    }
+
+    #[test]
+    fn character_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand("'a'ascii", false),
+            r#"crate ::custom_literal ::character ::ascii !('a' "'a'" @ character)"#
+        );
+    }
+
+    #[test]
+    fn byte_character_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand("b'a'ascii", false),
+            r#"crate ::custom_literal ::byte_character ::ascii !(97 "b'a'" @ byte_character)"#
+        );
+    }
+
+    #[test]
+    fn byte_string_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand(r#"b"foo"bar"#, false),
+            concat!(
+                r#"crate ::custom_literal ::byte_string ::bar !"#,
+                r#"(b"foo" b"foo" @ cooked "b\"foo\"" @ byte_string)"#,
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(has_c_string)]
+    fn c_string_literal_dispatches_to_custom_literal() {
+        assert_eq!(
+            expand(r#"c"foo"bar"#, false),
+            r#"crate ::custom_literal ::c_string ::bar !(c"foo" "c\"foo\"" @ c_string)"#
+        );
+    }
+
+    #[test]
+    fn multi_root_gives_self_and_super_roots_a_super_hop_same_as_a_bare_path() {
+        // Only `crate::...` is already absolute from inside the nested scratch module; a bare
+        // path, `self::...`, and `super::...` are all resolved relative to where `#[culit]` was
+        // invoked, so all three need the extra `super::` hop to still find them from in there.
+        // `self::` can't simply be prefixed with `super::` though (`super::self::...` is a
+        // syntax error, since `self` is only valid in path start position) - it's replaced by
+        // `super::` outright instead.
+        let roots: Vec<TokenStream> = vec![
+            "self::literals".parse().unwrap(),
+            "super::literals".parse().unwrap(),
+            "crate::literals".parse().unwrap(),
+            "other::literals".parse().unwrap(),
+        ];
+        let out = transform("100km".parse().unwrap(), &roots, false).to_string();
+        assert_eq!(
+            out,
+            concat!(
+                "{ mod __culit_literals { ",
+                "pub (crate) use super ::literals ::integer ::* ; ",
+                "pub (crate) use super ::super :: literals ::integer ::* ; ",
+                "pub (crate) use crate :: literals ::integer ::* ; ",
+                "pub (crate) use super ::other :: literals ::integer ::* ; ",
+                "} __culit_literals ::km !(100 \"100\" @ dec \"100\" @ integer) }",
+            )
+        );
+    }
+}
@@ -81,6 +81,13 @@ fn integer_literals() {
     assert_eq!(0b1111_1111_1111_1111id, 0xFFFF);
     assert_eq!(0o7_7_7_7id, 0o7777);
     assert_eq!(0xFFFF_FFFFid, 0xFFFFFFFF);
+
+    // A leading `-` in unary position is folded into the literal
+    assert_eq!(-10id, -10);
+    assert_eq!(-0x10id, -16);
+    let a: i64 = 5;
+    // ... but binary subtraction is left alone
+    assert_eq!(a - 10id, -5);
 }
 
 #[test]
@@ -135,16 +142,22 @@ fn float() {
     assert_eq!(0.123id, 0.123);
     assert_eq!(0.123e7id, 0.123e7);
     assert_eq!(0.123e-7id, 0.123e-7);
+
+    // A leading `-` in unary position is folded into the literal
+    assert_eq!(-70.0id, -70.0);
 }
 
 #[test]
 #[culit]
 fn str() {
-    assert_eq!("foo"id, stringify!("foo"));
-    assert_eq!("\nfoo"id, stringify!("\nfoo"));
-    assert_eq!(r"foo"id, stringify!("foo"));
-    assert_eq!(r#"foo"#id, stringify!("foo"));
-    assert_eq!(r#"foo\"#id, stringify!("foo\\"));
+    assert_eq!("foo"id, ("foo", "foo", "cooked"));
+    // `$raw` keeps the escape un-interpreted; `\nfoo` is written here to mean the 5
+    // literal characters `\`, `n`, `f`, `o`, `o`, not a newline followed by `foo`
+    assert_eq!("\nfoo"id, ("\nfoo", "\\nfoo", "cooked"));
+    // Raw strings have nothing to unescape, so `$value` and `$raw` agree
+    assert_eq!(r"foo"id, ("foo", "foo", "raw"));
+    assert_eq!(r#"foo"#id, ("foo", "foo", "raw"));
+    assert_eq!(r#"foo\"#id, ("foo\\", "foo\\", "raw"));
 }
 
 #[test]
@@ -156,25 +169,70 @@ fn byte_char() {
 #[test]
 #[culit]
 fn byte_str() {
-    assert_eq!(b"hello"id, stringify!(b"hello"));
-    assert_eq!(b"hell\\o"id, stringify!(b"hell\\o"));
-    assert_eq!(br"hell\o"id, stringify!(b"hell\\o"));
-    assert_eq!(br#"hello"#id, stringify!(b"hello"));
+    assert_eq!(b"hello"id, (&b"hello"[..], &b"hello"[..], "cooked"));
+    // `$raw` keeps both backslashes the user wrote, unlike `$value`'s single unescaped one
+    assert_eq!(
+        b"hell\\o"id,
+        (&b"hell\\o"[..], &b"hell\\\\o"[..], "cooked")
+    );
+    // Raw strings have nothing to unescape, so `$value` and `$raw` agree
+    assert_eq!(br"hell\o"id, (&b"hell\\o"[..], &b"hell\\o"[..], "raw"));
+    assert_eq!(br#"hello"#id, (&b"hello"[..], &b"hello"[..], "raw"));
 }
 
 #[test]
 #[culit]
 fn char() {
-    assert_eq!('a'id, stringify!('a'));
+    assert_eq!('a'id, stringify!('a' "'a'" @ character));
 }
 
 #[test]
 #[culit]
 fn c_str() {
-    assert_eq!(c"hello"id, stringify!(c"hello"));
-    assert_eq!(c"hell\\o"id, stringify!(c"hell\\o"));
-    assert_eq!(cr"hell\o"id, stringify!(c"hell\\o"));
-    assert_eq!(cr#"hello"#id, stringify!(c"hello"));
+    assert_eq!(c"hello"id, stringify!(c"hello" "c\"hello\"" @ c_string));
+    assert_eq!(
+        c"hell\\o"id,
+        stringify!(c"hell\\o" "c\"hell\\\\o\"" @ c_string)
+    );
+    assert_eq!(
+        cr"hell\o"id,
+        stringify!(c"hell\\o" "cr\"hell\\o\"" @ c_string)
+    );
+    assert_eq!(
+        cr#"hello"#id,
+        stringify!(c"hello" "cr#\"hello\"#" @ c_string)
+    );
+}
+
+#[test]
+#[culit(path = other_custom_literal)]
+fn redirected_path() {
+    assert_eq!(10id, 10);
+}
+
+mod other_custom_literal {
+    pub(crate) use super::custom_literal::integer;
+}
+
+#[test]
+#[culit(path = other_custom_literal, path = self::more_custom_literal)]
+fn multiple_redirected_paths() {
+    // Resolved via `other_custom_literal` (a bare path, same as a single `path = ...` root)
+    assert_eq!(10id, 10);
+    // Resolved via `self::more_custom_literal` (a `self::`-qualified root) - this only compiles
+    // if the multi-root glob-import hop treats `self::` the same as a bare path
+    assert_eq!(10two, 20);
+}
+
+mod more_custom_literal {
+    pub mod integer {
+        macro_rules! two {
+            ($value:literal $_raw:literal $(@ $_base:ident)? $_raw_source:literal @ $_kind:ident) => {{
+                ($value as i64) * 2
+            }};
+        }
+        pub(crate) use two;
+    }
 }
 
 mod custom_literal {
@@ -189,24 +247,41 @@ mod custom_literal {
 
     pub mod integer {
         macro_rules! id {
-            ($value:literal) => {{
+            ($value:literal $_raw:literal $(@ $_base:ident)? @ neg $_raw_source:literal @ $_kind:ident) => {{
+                -($value as i64)
+            }};
+            ($value:literal $_raw:literal $(@ $_base:ident)? $_raw_source:literal @ $_kind:ident) => {{
                 $value as i64
             }};
         }
         pub(crate) use id;
     }
 
-    pub mod float {
+    pub mod decimal {
         macro_rules! id {
-            ($value:literal) => {
-                $value as f32
+            // `$raw_source` is the exact "integral.fractional(e)exponent" text (underscores and
+            // all, sign already folded out via `@ neg`) - parsing it directly, rather than
+            // recombining `$integral`/`$fractional`/`$frac_len`/`$exponent` by hand, sidesteps
+            // `$exponent` defaulting to `1` rather than `0` when no `e...` was written at all
+            // (see the crate doc's "exponent" bullet), which a naive `10f32.powi($exponent)`
+            // would otherwise double-count as an extra `* 10`.
+            ($_integral:literal $_fractional:literal $_frac_len:literal $_exponent:literal $_raw:literal @ neg $raw_source:literal @ $_kind:ident) => {
+                -$raw_source.replace('_', "").parse::<f32>().unwrap()
+            };
+            ($_integral:literal $_fractional:literal $_frac_len:literal $_exponent:literal $_raw:literal $raw_source:literal @ $_kind:ident) => {
+                $raw_source.replace('_', "").parse::<f32>().unwrap()
             };
         }
         pub(crate) use id;
     }
 
     pub mod string {
-        pub(crate) use super::id;
+        macro_rules! id {
+            ($value:literal $raw:literal @ $style:ident $_raw_source:literal @ $_kind:ident) => {
+                ($value, $raw, stringify!($style))
+            };
+        }
+        pub(crate) use id;
     }
 
     pub mod character {
@@ -215,7 +290,7 @@ mod custom_literal {
 
     pub mod byte_character {
         macro_rules! id {
-            ($value:literal) => {{
+            ($value:literal $_raw_source:literal @ $_kind:ident) => {{
                 $value
             }};
         }
@@ -223,7 +298,12 @@ mod custom_literal {
     }
 
     pub mod byte_string {
-        pub(crate) use super::id;
+        macro_rules! id {
+            ($value:literal $raw:literal @ $style:ident $_raw_source:literal @ $_kind:ident) => {
+                (&$value[..], &$raw[..], stringify!($style))
+            };
+        }
+        pub(crate) use id;
     }
 
     pub mod c_string {
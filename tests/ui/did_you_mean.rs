@@ -0,0 +1,11 @@
+// In `strict` mode, suffixes that are a near-miss of a real one get an actionable suggestion
+// instead of being silently dispatched to a (likely nonexistent) `custom_literal` macro.
+//
+// I expect the error to be a "did you mean" note, with its span exactly at the literal.
+
+use culit::culit;
+
+#[culit(strict)]
+fn foo() {
+    let a = 10u3;
+}
@@ -4,7 +4,7 @@
 mod custom_literal {
     pub mod int {
         macro_rules! km {
-            ($value:literal $base:literal) => {};
+            ($value:literal $raw:literal $(@ $base:ident)? $raw_source:literal @ $kind:ident) => {};
         }
         pub(crate) use km;
     }
@@ -8,7 +8,7 @@ struct Kilometer(u32);
 mod custom_literal {
     pub mod int {
         macro_rules! km {
-            ($value:literal $base:literal) => {
+            ($value:literal $raw:literal $(@ $base:ident)? $raw_source:literal @ $kind:ident) => {
                 const { std::num::NonZeroU32::new($value).unwrap() }
             };
         }
@@ -0,0 +1,37 @@
+//! With more than one `path = ...`, a custom literal expands to a block (to glob-import every
+//! root's `custom_literal` module unambiguously), so it only works in expression position.
+//! I expect a pattern-position error, since `10km` is being matched against here.
+
+use culit::culit;
+
+mod custom_literal_a {
+    pub mod integer {
+        macro_rules! km {
+            ($($tt:tt)*) => {
+                100
+            };
+        }
+        pub(crate) use km;
+    }
+}
+
+mod custom_literal_b {
+    pub mod integer {
+        macro_rules! mi {
+            ($($tt:tt)*) => {
+                100
+            };
+        }
+        pub(crate) use mi;
+    }
+}
+
+#[culit(path = crate::custom_literal_a, path = crate::custom_literal_b)]
+fn foo(x: i64) {
+    match x {
+        10km => {}
+        _ => {}
+    }
+}
+
+fn main() {}